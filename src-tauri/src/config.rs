@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+const APP_NAME: &str = "textdb";
+const RECENT_FILES_CAP: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowGeometry {
+  pub width: f64,
+  pub height: f64,
+  pub x: Option<f64>,
+  pub y: Option<f64>,
+}
+
+impl Default for WindowGeometry {
+  fn default() -> Self {
+    Self {
+      width: 1024.0,
+      height: 768.0,
+      x: None,
+      y: None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+  pub last_db_path: Option<String>,
+  pub recent_files: Vec<String>,
+  pub window: WindowGeometry,
+  pub theme: String,
+}
+
+impl Default for AppConfig {
+  fn default() -> Self {
+    Self {
+      last_db_path: None,
+      recent_files: Vec::new(),
+      window: WindowGeometry::default(),
+      theme: "system".to_string(),
+    }
+  }
+}
+
+pub struct ConfigState(pub Mutex<AppConfig>);
+
+/// Loads the persisted config, falling back to defaults if none exists yet
+/// or the file on disk can't be parsed.
+pub fn load() -> AppConfig {
+  confy::load(APP_NAME, None).unwrap_or_default()
+}
+
+fn push_recent_file(config: &mut AppConfig, path: &str) {
+  config.recent_files.retain(|existing| existing != path);
+  config.recent_files.insert(0, path.to_string());
+  config.recent_files.truncate(RECENT_FILES_CAP);
+}
+
+/// Records paths that were just opened (via file association, single
+/// instance forwarding, etc.) into the recent-files ring and persists them.
+pub fn record_opened_paths(state: &ConfigState, paths: &[String]) {
+  if paths.is_empty() {
+    return;
+  }
+  let mut config = state.0.lock().expect("config lock");
+  for path in paths {
+    push_recent_file(&mut config, path);
+  }
+  config.last_db_path = paths.last().cloned();
+  let _ = confy::store(APP_NAME, None, config.clone());
+}
+
+#[tauri::command]
+pub fn get_config(state: tauri::State<ConfigState>) -> AppConfig {
+  state.0.lock().expect("config lock").clone()
+}
+
+#[tauri::command]
+pub fn set_config(state: tauri::State<ConfigState>, config: AppConfig) -> Result<(), String> {
+  let mut guard = state.0.lock().expect("config lock");
+  *guard = config.clone();
+  confy::store(APP_NAME, None, config).map_err(|err| err.to_string())
+}
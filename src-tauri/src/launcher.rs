@@ -0,0 +1,60 @@
+use serde::Serialize;
+use std::env;
+use tauri::AppHandle;
+use tauri_plugin_shell::ShellExt;
+use which::which;
+
+#[cfg(target_os = "windows")]
+const PLATFORM_DEFAULTS: &[&str] = &["notepad.exe"];
+#[cfg(target_os = "macos")]
+const PLATFORM_DEFAULTS: &[&str] = &["open"];
+#[cfg(all(unix, not(target_os = "macos")))]
+const PLATFORM_DEFAULTS: &[&str] = &["xdg-open"];
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum LauncherError {
+  NoEditorFound,
+  SpawnFailed { message: String },
+}
+
+impl std::fmt::Display for LauncherError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      LauncherError::NoEditorFound => {
+        write!(f, "no external editor found on PATH, $EDITOR, $VISUAL, or platform defaults")
+      }
+      LauncherError::SpawnFailed { message } => write!(f, "failed to launch external editor: {message}"),
+    }
+  }
+}
+
+impl std::error::Error for LauncherError {}
+
+/// Resolves an editor, preferring an explicitly requested program, then
+/// `$EDITOR`/`$VISUAL`, then a platform default, each checked against `$PATH`.
+fn resolve_editor(program: Option<&str>) -> Option<String> {
+  let candidates = program
+    .map(|program| program.to_string())
+    .into_iter()
+    .chain(env::var("EDITOR").ok())
+    .chain(env::var("VISUAL").ok())
+    .chain(PLATFORM_DEFAULTS.iter().map(|default| default.to_string()));
+
+  candidates
+    .filter_map(|candidate| which(&candidate).ok())
+    .next()
+    .map(|resolved| resolved.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub fn open_external(app: AppHandle, path: String, program: Option<String>) -> Result<(), LauncherError> {
+  let editor = resolve_editor(program.as_deref()).ok_or(LauncherError::NoEditorFound)?;
+  app
+    .shell()
+    .command(editor)
+    .args([path])
+    .spawn()
+    .map_err(|err| LauncherError::SpawnFailed { message: err.to_string() })?;
+  Ok(())
+}
@@ -1,9 +1,15 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod config;
+mod launcher;
+mod metadata;
+mod tray;
+
+use std::path::Path;
 use std::sync::Mutex;
 use tauri::{Emitter, Manager, RunEvent, Wry};
 
-struct PendingOpens(Mutex<Vec<String>>);
+pub(crate) struct PendingOpens(pub(crate) Mutex<Vec<String>>);
 
 #[tauri::command]
 fn take_pending_opens(state: tauri::State<PendingOpens>) -> Vec<String> {
@@ -11,13 +17,83 @@ fn take_pending_opens(state: tauri::State<PendingOpens>) -> Vec<String> {
   pending.drain(..).collect()
 }
 
+/// Resolves a process argument to an absolute file path relative to
+/// `base_dir`, or `None` if it looks like a flag or doesn't point at an
+/// existing file. `base_dir` must be the working directory the argument
+/// was passed from, since relative args are meaningless against our own
+/// (possibly different) process cwd.
+fn resolve_file_arg(arg: &str, base_dir: &Path) -> Option<String> {
+  if arg.starts_with('-') {
+    return None;
+  }
+  let path = Path::new(arg);
+  let path = if path.is_absolute() { path.to_path_buf() } else { base_dir.join(path) };
+  if !path.is_file() {
+    return None;
+  }
+  let absolute = path.canonicalize().unwrap_or(path);
+  Some(absolute.to_string_lossy().to_string())
+}
+
+/// On Windows/Linux a double-clicked associated file arrives as a plain
+/// process argument instead of through `RunEvent::Opened`, so seed
+/// `PendingOpens` from `std::env::args()` before the window loads.
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+fn seed_pending_opens_from_args(app: &tauri::App<Wry>) {
+  let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+  let paths: Vec<String> = std::env::args()
+    .skip(1)
+    .filter_map(|arg| resolve_file_arg(&arg, &cwd))
+    .collect();
+  if paths.is_empty() {
+    return;
+  }
+  let state = app.state::<PendingOpens>();
+  let mut pending = state.0.lock().expect("pending opens lock");
+  pending.extend(paths.iter().cloned());
+  drop(pending);
+  config::record_opened_paths(&app.state::<config::ConfigState>(), &paths);
+}
+
 fn main() {
   tauri::Builder::default()
+    .plugin(tauri_plugin_single_instance::init(|app, argv, cwd| {
+      let cwd = Path::new(&cwd);
+      let paths: Vec<String> = argv
+        .iter()
+        .skip(1)
+        .filter_map(|arg| resolve_file_arg(arg, cwd))
+        .collect();
+      if !paths.is_empty() {
+        let state = app.state::<PendingOpens>();
+        let mut pending = state.0.lock().expect("pending opens lock");
+        pending.extend(paths.iter().cloned());
+        config::record_opened_paths(&app.state::<config::ConfigState>(), &paths);
+      }
+      if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+      }
+      if !paths.is_empty() {
+        let _ = app.emit("file-opened", paths);
+      }
+    }))
     .setup(|app| {
       app.manage(PendingOpens(Mutex::new(Vec::new())));
+      app.manage(config::ConfigState(Mutex::new(config::load())));
+      #[cfg(any(target_os = "windows", target_os = "linux"))]
+      seed_pending_opens_from_args(app);
+      tray::create(&app.handle())?;
       Ok(())
     })
-    .invoke_handler(tauri::generate_handler![take_pending_opens])
+    .invoke_handler(tauri::generate_handler![
+      take_pending_opens,
+      config::get_config,
+      config::set_config,
+      metadata::get_entry_metadata,
+      metadata::list_directory,
+      launcher::open_external
+    ])
     .plugin(tauri_plugin_dialog::init())
     .plugin(tauri_plugin_clipboard_manager::init())
     .plugin(tauri_plugin_fs::init())
@@ -39,6 +115,8 @@ fn main() {
             let state = app.state::<PendingOpens>();
             let mut pending = state.0.lock().expect("pending opens lock");
             pending.extend(paths.iter().cloned());
+            drop(pending);
+            config::record_opened_paths(&app.state::<config::ConfigState>(), &paths);
             let _ = app.emit("file-opened", paths);
           }
         })
@@ -0,0 +1,117 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EntryMetadata {
+  pub name: String,
+  pub path: String,
+  pub size: u64,
+  pub is_dir: bool,
+  pub is_file: bool,
+  pub is_symlink: bool,
+  pub permissions: String,
+  pub child_count: Option<u64>,
+  pub created: Option<u64>,
+  pub modified: Option<u64>,
+  pub accessed: Option<u64>,
+  pub error: Option<String>,
+}
+
+#[cfg(unix)]
+fn format_permissions(metadata: &fs::Metadata) -> String {
+  use std::os::unix::fs::PermissionsExt;
+  let mode = metadata.permissions().mode();
+  let owner = (mode >> 6) & 0o7;
+  let r = if owner & 0b100 != 0 { 'r' } else { '-' };
+  let w = if owner & 0b010 != 0 { 'w' } else { '-' };
+  let x = if owner & 0b001 != 0 { 'x' } else { '-' };
+  format!("{:04o} ({}{}{})", mode & 0o777, r, w, x)
+}
+
+#[cfg(not(unix))]
+fn format_permissions(metadata: &fs::Metadata) -> String {
+  if metadata.permissions().readonly() {
+    "0444 (r--)".to_string()
+  } else {
+    "0644 (rw-)".to_string()
+  }
+}
+
+fn system_time_to_secs(time: std::io::Result<SystemTime>) -> Option<u64> {
+  time.ok()?.duration_since(UNIX_EPOCH).ok().map(|duration| duration.as_secs())
+}
+
+/// Reads metadata for `path`, falling back to the symlink's own metadata
+/// when the symlink target can't be stat'd (e.g. a broken symlink) so the
+/// entry still renders instead of erroring out the whole listing.
+fn read_entry_metadata(path: &Path) -> Result<EntryMetadata, String> {
+  let symlink_metadata = fs::symlink_metadata(path).map_err(|err| err.to_string())?;
+  let is_symlink = symlink_metadata.file_type().is_symlink();
+  let metadata = fs::metadata(path).unwrap_or_else(|_| symlink_metadata.clone());
+  let name = path
+    .file_name()
+    .map(|name| name.to_string_lossy().to_string())
+    .unwrap_or_else(|| path.to_string_lossy().to_string());
+  let absolute = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+  let child_count = if metadata.is_dir() {
+    fs::read_dir(path).ok().map(|entries| entries.count() as u64)
+  } else {
+    None
+  };
+
+  Ok(EntryMetadata {
+    name,
+    path: absolute.to_string_lossy().to_string(),
+    size: metadata.len(),
+    is_dir: metadata.is_dir(),
+    is_file: metadata.is_file(),
+    is_symlink,
+    permissions: format_permissions(&metadata),
+    child_count,
+    created: system_time_to_secs(metadata.created()),
+    modified: system_time_to_secs(metadata.modified()),
+    accessed: system_time_to_secs(metadata.accessed()),
+    error: None,
+  })
+}
+
+/// Builds metadata for a directory child, never failing the overall
+/// listing: an entry that can't be stat'd at all (e.g. removed mid-scan,
+/// permission denied) becomes a minimal placeholder with `error` set.
+fn read_child_metadata(path: &Path) -> EntryMetadata {
+  read_entry_metadata(path).unwrap_or_else(|err| EntryMetadata {
+    name: path
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_else(|| path.to_string_lossy().to_string()),
+    path: path.to_string_lossy().to_string(),
+    size: 0,
+    is_dir: false,
+    is_file: false,
+    is_symlink: false,
+    permissions: String::new(),
+    child_count: None,
+    created: None,
+    modified: None,
+    accessed: None,
+    error: Some(err),
+  })
+}
+
+#[tauri::command]
+pub fn get_entry_metadata(path: String) -> Result<EntryMetadata, String> {
+  read_entry_metadata(Path::new(&path))
+}
+
+#[tauri::command]
+pub fn list_directory(path: String) -> Result<Vec<EntryMetadata>, String> {
+  Ok(
+    fs::read_dir(&path)
+      .map_err(|err| err.to_string())?
+      .filter_map(|entry| entry.ok())
+      .map(|entry| read_child_metadata(&entry.path()))
+      .collect(),
+  )
+}
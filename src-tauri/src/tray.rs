@@ -0,0 +1,96 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{
+  menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem, Submenu},
+  tray::TrayIconBuilder,
+  AppHandle, Emitter, Manager, WebviewUrl, WebviewWindowBuilder, Wry,
+};
+use tauri_plugin_dialog::DialogExt;
+
+use crate::config::ConfigState;
+use crate::PendingOpens;
+
+const RECENT_ITEM_PREFIX: &str = "recent:";
+
+static WINDOW_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Generates a fresh, unique label for a new window (the primary window
+/// always owns the `"main"` label, so extra windows can't reuse it).
+fn next_window_label() -> String {
+  format!("window-{}", WINDOW_COUNTER.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Builds the tray icon and its menu, populated with the persisted
+/// recent-files list.
+pub fn create(app: &AppHandle<Wry>) -> tauri::Result<()> {
+  let menu = build_menu(app)?;
+  TrayIconBuilder::new()
+    .menu(&menu)
+    .on_menu_event(handle_event)
+    .build(app)?;
+  Ok(())
+}
+
+fn build_menu(app: &AppHandle<Wry>) -> tauri::Result<Menu<Wry>> {
+  let recent_files = app
+    .state::<ConfigState>()
+    .0
+    .lock()
+    .expect("config lock")
+    .recent_files
+    .clone();
+
+  let new_window = MenuItem::with_id(app, "new-window", "New window", true, None::<&str>)?;
+  let open = MenuItem::with_id(app, "open", "Open…", true, None::<&str>)?;
+  let separator = PredefinedMenuItem::separator(app)?;
+  let quit = PredefinedMenuItem::quit(app, None)?;
+
+  let recent_items = recent_files
+    .iter()
+    .map(|path| MenuItem::with_id(app, format!("{RECENT_ITEM_PREFIX}{path}"), path, true, None::<&str>))
+    .collect::<tauri::Result<Vec<_>>>()?;
+  let recent_refs: Vec<&dyn tauri::menu::IsMenuItem<Wry>> = recent_items
+    .iter()
+    .map(|item| item as &dyn tauri::menu::IsMenuItem<Wry>)
+    .collect();
+  let recent_menu = Submenu::with_items(app, "Recent", !recent_items.is_empty(), &recent_refs)?;
+
+  Menu::with_items(app, &[&new_window, &open, &recent_menu, &separator, &quit])
+}
+
+/// Dispatches a clicked tray menu item to the matching action.
+pub fn handle_event(app: &AppHandle<Wry>, event: MenuEvent) {
+  match event.id().as_ref() {
+    "new-window" => {
+      let _ = WebviewWindowBuilder::new(app, next_window_label(), WebviewUrl::App("index.html".into())).build();
+    }
+    "open" => {
+      let app = app.clone();
+      app.dialog().file().pick_file(move |file| {
+        if let Some(path) = file.and_then(|selected| selected.into_path().ok()) {
+          open_path(&app, path.to_string_lossy().to_string());
+        }
+      });
+    }
+    // "quit" isn't handled here: `PredefinedMenuItem::quit` exits the app
+    // itself and never reaches this handler.
+    id => {
+      if let Some(path) = id.strip_prefix(RECENT_ITEM_PREFIX) {
+        open_path(app, path.to_string());
+      }
+    }
+  }
+}
+
+fn open_path(app: &AppHandle<Wry>, path: String) {
+  app
+    .state::<PendingOpens>()
+    .0
+    .lock()
+    .expect("pending opens lock")
+    .push(path.clone());
+  if let Some(window) = app.get_webview_window("main") {
+    let _ = window.show();
+    let _ = window.set_focus();
+  }
+  let _ = app.emit("file-opened", vec![path]);
+}